@@ -0,0 +1,774 @@
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "viz")]
+use std::io::{stdout, Write};
+#[cfg(feature = "viz")]
+use std::thread::sleep;
+#[cfg(feature = "viz")]
+use termion::raw::IntoRawMode;
+#[cfg(feature = "viz")]
+use termion::{clear, cursor};
+
+// constant slice of tuples used to build series of Directions
+const DIRECTIONS: &[(i32, i32)] = &[(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+// Smallest integer `q` such that `q * d >= n`, for positive `n` and `d`.
+fn div_ceil(n: i32, d: i32) -> i32 {
+    (n + d - 1) / d
+}
+
+/// Search strategy used by [`Ability::find_shortest_path_with`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, clap::ValueEnum)]
+pub enum Mode {
+    #[default]
+    Bfs,
+    Greedy,
+    AStar,
+}
+
+/// Error returned by [`Ability::find_shortest_path_budgeted`] when the
+/// search's time budget expires before the goal is dequeued.
+#[derive(Debug, Clone, Copy)]
+pub struct SearchTimeout {
+    /// Steps from `start` to the node BFS was about to expand when the
+    /// budget expired, i.e. the current BFS frontier depth. Nodes up to
+    /// one step further may already be queued but not yet dequeued.
+    pub best_distance: i32,
+}
+impl std::fmt::Display for SearchTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "search timed out at frontier depth {}",
+            self.best_distance
+        )
+    }
+}
+impl Error for SearchTimeout {}
+
+// Frontier entry for the priority-queue based modes (`Greedy`, `AStar`).
+// `BinaryHeap` is a max-heap, so ordering is reversed to pop the node
+// with the lowest `priority` first.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Frontier {
+    priority: i32,
+    g: i32,
+    position: Position,
+}
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Describes the size of the knight's move. Given a square board and the
+/// current position, the knight has a series of valid moves. The knight
+/// hosts the ability to find the shortest path from the origin to the
+/// oposite diagonal corner of the board.
+#[derive(Debug, Default, Clone)]
+pub struct Ability(i32, i32);
+impl Ability {
+    fn reverse(&self) -> Self {
+        Ability(self.1, self.0)
+    }
+    // Combine the ability with a direction to create a move.
+    fn moves(&self) -> Vec<Move> {
+        let directions = Vec::from(DIRECTIONS).into_iter().map(Direction::from);
+        if self.0 == self.1 {
+            return directions.map(|d| Move::new(self, &d)).collect();
+        }
+        directions
+            .clone()
+            .map(|d| Move::new(self, &d))
+            .chain(directions.map(|d| Move::new(&self.reverse(), &d)))
+            .collect::<Vec<_>>()
+    }
+    // Find valid moves given the current position of the knight.
+    fn valid_moves(&self, current: &Position, board_size: &Position) -> Vec<Position> {
+        self.moves()
+            .into_iter()
+            .filter_map(|move_| {
+                // Calculate the new position
+                let new_r = current.0 + move_.0;
+                let new_c = current.1 + move_.1;
+
+                // Check if the new position is valid
+                Position::try_from((new_r, new_c), board_size)
+            })
+            .collect()
+    }
+    // Find valid moves given the current position, rejecting landing
+    // squares that are blocked on `board`.
+    fn valid_moves_on(&self, current: &Position, board: &Board) -> Vec<Position> {
+        self.valid_moves(current, &board.size)
+            .into_iter()
+            .filter(|pos| !board.blocked.contains(pos))
+            .collect()
+    }
+    pub fn find_shortest_path(&self, start: &Position, goal: &Position) -> Path {
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+        let mut parents = HashMap::new();
+
+        queue.push_back(start.clone());
+        visited.insert(start.clone());
+
+        while let Some(current_pos) = queue.pop_front() {
+            if current_pos == *goal {
+                return Path::new(start, goal, parents);
+            }
+
+            for next_move in self.valid_moves(&current_pos, goal) {
+                if !visited.contains(&next_move) {
+                    queue.push_back(next_move.clone());
+                    visited.insert(next_move.clone());
+                    parents.insert(next_move, current_pos.clone());
+                }
+            }
+        }
+
+        Path::empty()
+    }
+    /// Find the shortest path on a `board` that carries impassable cells,
+    /// returning [`Path::empty`] when the goal can't be reached around
+    /// the obstacles.
+    pub fn find_shortest_path_on(&self, start: &Position, goal: &Position, board: &Board) -> Path {
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+        let mut parents = HashMap::new();
+
+        queue.push_back(start.clone());
+        visited.insert(start.clone());
+
+        while let Some(current_pos) = queue.pop_front() {
+            if current_pos == *goal {
+                return Path::new(start, goal, parents);
+            }
+
+            for next_move in self.valid_moves_on(&current_pos, board) {
+                if !visited.contains(&next_move) {
+                    queue.push_back(next_move.clone());
+                    visited.insert(next_move.clone());
+                    parents.insert(next_move, current_pos.clone());
+                }
+            }
+        }
+
+        Path::empty()
+    }
+    /// Find the minimum-cost path to `goal`, where `cost` gives the
+    /// positive weight of entering a destination square (e.g. difficult
+    /// terrain). Uses Dijkstra's algorithm in place of plain BFS, and
+    /// returns the path alongside its total cost.
+    pub fn find_cheapest_path(
+        &self,
+        start: &Position,
+        goal: &Position,
+        cost: &dyn Fn(&Position) -> u32,
+    ) -> (Path, u32) {
+        let mut heap = BinaryHeap::new();
+        let mut best_cost: HashMap<Position, u32> = HashMap::new();
+        let mut parents = HashMap::new();
+
+        best_cost.insert(start.clone(), 0);
+        heap.push(Reverse((0u32, start.clone())));
+
+        while let Some(Reverse((acc_cost, position))) = heap.pop() {
+            if position == *goal {
+                return (Path::new(start, goal, parents), acc_cost);
+            }
+            if acc_cost > best_cost.get(&position).copied().unwrap_or(u32::MAX) {
+                continue; // a cheaper route to this node was already finalized
+            }
+            for next in self.valid_moves(&position, goal) {
+                let next_cost = acc_cost + cost(&next);
+                if next_cost < best_cost.get(&next).copied().unwrap_or(u32::MAX) {
+                    best_cost.insert(next.clone(), next_cost);
+                    parents.insert(next.clone(), position.clone());
+                    heap.push(Reverse((next_cost, next)));
+                }
+            }
+        }
+
+        (Path::empty(), 0)
+    }
+    /// Find the shortest path by expanding frontiers from both `start`
+    /// and `goal` at once, always advancing whichever side's frontier is
+    /// currently smaller. Knight moves are symmetric (the move set is
+    /// closed under negation), so the same `valid_moves` works in both
+    /// directions. Roughly halves the explored depth, and so the visited
+    /// set, compared to single-source BFS.
+    pub fn find_shortest_path_bidirectional(&self, start: &Position, goal: &Position) -> Path {
+        let mut frontier_fwd = VecDeque::from([start.clone()]);
+        let mut visited_fwd = HashSet::from([start.clone()]);
+        let mut parents_fwd = HashMap::new();
+
+        let mut frontier_bwd = VecDeque::from([goal.clone()]);
+        let mut visited_bwd = HashSet::from([goal.clone()]);
+        let mut parents_bwd = HashMap::new();
+
+        while !frontier_fwd.is_empty() && !frontier_bwd.is_empty() {
+            let advance_fwd = frontier_fwd.len() <= frontier_bwd.len();
+            let (frontier, visited, visited_other, parents) = if advance_fwd {
+                (&mut frontier_fwd, &mut visited_fwd, &visited_bwd, &mut parents_fwd)
+            } else {
+                (&mut frontier_bwd, &mut visited_bwd, &visited_fwd, &mut parents_bwd)
+            };
+
+            let current = frontier.pop_front().expect("frontier checked non-empty above");
+            if visited_other.contains(&current) {
+                return Path::stitched(start, goal, &current, &parents_fwd, &parents_bwd);
+            }
+
+            for next_move in self.valid_moves(&current, goal) {
+                if !visited.contains(&next_move) {
+                    visited.insert(next_move.clone());
+                    parents.insert(next_move.clone(), current.clone());
+                    frontier.push_back(next_move);
+                }
+            }
+        }
+
+        Path::empty()
+    }
+    /// Like [`Ability::find_shortest_path`], but gives up once `budget`
+    /// elapses instead of running to completion. The elapsed time is
+    /// only checked every `CHECK_EVERY` expansions, so the clock read
+    /// itself doesn't dominate tight loops on small boards.
+    pub fn find_shortest_path_budgeted(
+        &self,
+        start: &Position,
+        goal: &Position,
+        budget: Duration,
+    ) -> Result<Path, SearchTimeout> {
+        const CHECK_EVERY: u32 = 64;
+
+        let clock = Instant::now();
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+        let mut parents = HashMap::new();
+        let mut distance = HashMap::new();
+        let mut best_distance;
+        let mut expansions: u32 = 0;
+
+        queue.push_back(start.clone());
+        visited.insert(start.clone());
+        distance.insert(start.clone(), 0);
+
+        while let Some(current_pos) = queue.pop_front() {
+            best_distance = *distance.get(&current_pos).unwrap();
+
+            if current_pos == *goal {
+                return Ok(Path::new(start, goal, parents));
+            }
+
+            expansions += 1;
+            if expansions.is_multiple_of(CHECK_EVERY) && clock.elapsed() >= budget {
+                return Err(SearchTimeout { best_distance });
+            }
+
+            let next_distance = best_distance + 1;
+            for next_move in self.valid_moves(&current_pos, goal) {
+                if !visited.contains(&next_move) {
+                    visited.insert(next_move.clone());
+                    distance.insert(next_move.clone(), next_distance);
+                    parents.insert(next_move.clone(), current_pos.clone());
+                    queue.push_back(next_move);
+                }
+            }
+        }
+
+        Ok(Path::empty())
+    }
+    // Admissible lower bound on the number of remaining knight moves from
+    // `current` to `goal`: a single move changes the summed displacement
+    // `|dr|+|dc|` by at most `a+b`, and changes either coordinate by at
+    // most `max(a,b)`, so the larger of the two resulting bounds is still
+    // admissible.
+    fn heuristic(&self, current: &Position, goal: &Position) -> i32 {
+        let dr = (goal.0 - current.0).abs();
+        let dc = (goal.1 - current.1).abs();
+        if dr == 0 && dc == 0 {
+            return 0;
+        }
+        let ab_sum = self.0 + self.1;
+        let ab_max = self.0.max(self.1);
+        if ab_sum <= 0 || ab_max <= 0 {
+            // a degenerate ability (e.g. `Ability(0, 0)`) can never move,
+            // so nothing beyond the current square is reachable
+            return i32::MAX;
+        }
+        let by_sum = div_ceil(dr + dc, ab_sum);
+        let by_max = div_ceil(dr.max(dc), ab_max);
+        by_sum.max(by_max)
+    }
+    /// Find a path using the given search [`Mode`]. `Bfs` and `AStar`
+    /// return a shortest path; `Greedy` returns the first path it reaches,
+    /// which can take more steps than the shortest one.
+    pub fn find_shortest_path_with(&self, start: &Position, goal: &Position, mode: Mode) -> Path {
+        match mode {
+            Mode::Bfs => self.find_shortest_path(start, goal),
+            Mode::Greedy => self.find_priority_path(start, goal, false),
+            Mode::AStar => self.find_priority_path(start, goal, true),
+        }
+    }
+    // Shared priority-queue search backing `Greedy` and `AStar` modes.
+    // `relax` selects whether the frontier is ordered on `g + h` (A*) or
+    // on `h` alone (Greedy). Both modes relax a node's `g` whenever a
+    // cheaper route is found.
+    fn find_priority_path(&self, start: &Position, goal: &Position, relax: bool) -> Path {
+        let mut heap = BinaryHeap::new();
+        let mut best_g: HashMap<Position, i32> = HashMap::new();
+        let mut parents = HashMap::new();
+
+        best_g.insert(start.clone(), 0);
+        heap.push(Frontier {
+            priority: self.heuristic(start, goal),
+            g: 0,
+            position: start.clone(),
+        });
+
+        while let Some(Frontier { g, position, .. }) = heap.pop() {
+            if position == *goal {
+                return Path::new(start, goal, parents);
+            }
+            if g > best_g.get(&position).copied().unwrap_or(i32::MAX) {
+                continue; // a cheaper route to this node was already relaxed
+            }
+            for next in self.valid_moves(&position, goal) {
+                let next_g = g + 1;
+                if next_g < best_g.get(&next).copied().unwrap_or(i32::MAX) {
+                    best_g.insert(next.clone(), next_g);
+                    parents.insert(next.clone(), position.clone());
+                    let priority = if relax {
+                        next_g + self.heuristic(&next, goal)
+                    } else {
+                        self.heuristic(&next, goal)
+                    };
+                    heap.push(Frontier {
+                        priority,
+                        g: next_g,
+                        position: next,
+                    });
+                }
+            }
+        }
+
+        Path::empty()
+    }
+}
+/// A square board with a set of impassable cells that knight paths must
+/// route around.
+#[derive(Debug, Default, Clone)]
+pub struct Board {
+    size: Position,
+    blocked: HashSet<Position>,
+}
+impl Board {
+    pub fn new(size: Position, blocked: HashSet<Position>) -> Self {
+        Board { size, blocked }
+    }
+}
+#[derive(Debug, Default, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Position(i32, i32);
+impl Position {
+    fn is_valid(&self, board_size: &Position) -> bool {
+        let origin = Position(1, 1);
+        self.0 >= origin.0 && self.1 >= origin.1 && self.0 <= board_size.0 && self.1 <= board_size.1
+    }
+    fn try_from((a, b): (i32, i32), board_size: &Position) -> Option<Self> {
+        let pos = Position(a, b);
+        if pos.is_valid(board_size) {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+}
+/// A move is the application of a knight's ability to a direction.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+struct Move(i32, i32);
+impl Move {
+    fn new(knight: &Ability, direction: &Direction) -> Self {
+        Move(knight.0 * direction.0, knight.1 * direction.1)
+    }
+}
+#[derive(Debug, Default, Clone)]
+struct Direction(i32, i32);
+impl From<(i32, i32)> for Direction {
+    fn from((a, b): (i32, i32)) -> Self {
+        Direction(a, b)
+    }
+}
+#[derive(Debug, Default, Clone)]
+pub struct Path(Vec<Position>);
+impl std::fmt::Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut path = String::new();
+        for pos in &self.0 {
+            path.push_str(&format!("({}, {})\n", pos.0, pos.1));
+        }
+        write!(f, "{}", path)
+    }
+}
+impl Path {
+    fn new(start: &Position, goal: &Position, parents: HashMap<Position, Position>) -> Self {
+        let mut path = vec![goal.clone()];
+        let mut current = goal;
+
+        while current != start {
+            current = parents.get(current).unwrap();
+            path.push(current.clone());
+        }
+
+        path.reverse();
+        Path(path)
+    }
+    fn empty() -> Self {
+        Path(Vec::new())
+    }
+    // Stitch the two half-paths produced by a bidirectional search at the
+    // node where the forward and backward frontiers met.
+    fn stitched(
+        start: &Position,
+        goal: &Position,
+        meet: &Position,
+        parents_fwd: &HashMap<Position, Position>,
+        parents_bwd: &HashMap<Position, Position>,
+    ) -> Self {
+        let mut forward_half = vec![meet.clone()];
+        let mut current = meet;
+        while current != start {
+            current = parents_fwd.get(current).unwrap();
+            forward_half.push(current.clone());
+        }
+        forward_half.reverse();
+
+        let mut current = meet;
+        while current != goal {
+            current = parents_bwd.get(current).unwrap();
+            forward_half.push(current.clone());
+        }
+
+        Path(forward_half)
+    }
+    // specialized output for the problem
+    fn step_count(&self) -> i32 {
+        if self.0.is_empty() {
+            -1
+        } else {
+            (self.0.len() - 1) as i32
+        }
+    }
+    /// Sum of `cost` over each square entered along the path (the start
+    /// square itself is free to occupy).
+    pub fn total_cost(&self, cost: &dyn Fn(&Position) -> u32) -> u32 {
+        self.0.iter().skip(1).map(cost).sum()
+    }
+}
+
+#[cfg(feature = "viz")]
+impl Path {
+    /// Render a `board_size` grid in the terminal and animate the knight
+    /// hopping along this path, pausing `delay_ms` between frames.
+    /// Restores the cursor on exit.
+    pub fn animate(&self, board_size: &Position, delay_ms: u64) {
+        let Some(start) = self.0.first() else {
+            return;
+        };
+        let goal = self.0.last().unwrap();
+
+        let mut screen = stdout().into_raw_mode().expect("failed to enter raw mode");
+        write!(screen, "{}{}", clear::All, cursor::Hide).unwrap();
+
+        for (step, knight) in self.0.iter().enumerate() {
+            write!(screen, "{}", cursor::Goto(1, 1)).unwrap();
+            for r in 1..=board_size.0 {
+                for c in 1..=board_size.1 {
+                    let here = Position(r, c);
+                    let glyph = if here == *knight {
+                        'N'
+                    } else if here == *start {
+                        'S'
+                    } else if here == *goal {
+                        'G'
+                    } else if self.0[..step].contains(&here) {
+                        '*'
+                    } else {
+                        '.'
+                    };
+                    write!(screen, "{glyph} ").unwrap();
+                }
+                write!(screen, "\r\n").unwrap();
+            }
+            screen.flush().unwrap();
+            sleep(Duration::from_millis(delay_ms));
+        }
+
+        write!(screen, "{}", cursor::Show).unwrap();
+        screen.flush().unwrap();
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Reporting
+type Report = i32;
+// sentinel recorded when a budgeted search doesn't resolve in time
+const NOT_SOLVED_WITHIN_BUDGET: Report = -2;
+
+pub struct Reports {
+    data: VecDeque<Report>,
+    n: i32,
+}
+impl Reports {
+    // convert the Vec<Report> to a report that includes
+    // the results that apply to the mirrored knight (1,3 ~ 3,1).
+    pub fn finalize(mut self) -> Self {
+        let capacity = self.n * self.n;
+        let dummy: Vec<i32> = vec![0i32; capacity as usize];
+        let mut new_reports = VecDeque::from(dummy);
+        for r in 1..=self.n {
+            for c in 1..=self.n {
+                if r <= c {
+                    let Some(item) = self.data.pop_front() else {
+                        panic!("no item")
+                    };
+                    let slot: usize = self.get_idx(r, c);
+                    new_reports[slot] = item;
+                } else {
+                    let slot = self.get_idx(c, r);
+                    let item = new_reports[slot];
+                    let slot = self.get_idx(r, c);
+                    new_reports[slot] = item;
+                }
+            }
+        }
+        Reports {
+            data: new_reports,
+            n: self.n,
+        }
+    }
+    fn get_idx(&self, r: i32, c: i32) -> usize {
+        ((r - 1) * self.n + (c - 1)) as usize
+    }
+    pub fn print(&self) {
+        self.data.iter().enumerate().for_each(|(i, r)| {
+            if i % self.n as usize == 0 {
+                println!();
+            }
+            print!("{:3} ", r);
+        });
+    }
+    // fn that returns a Vec<Vec<i32>> for the problem
+    pub fn to_2dvec(mut self) -> Vec<Vec<i32>> {
+        self.data
+            .make_contiguous()
+            .chunks(self.n as usize)
+            .map(|chunk| chunk.to_vec())
+            .collect()
+    }
+    fn rows(&self) -> Vec<Vec<i32>> {
+        self.data
+            .iter()
+            .copied()
+            .collect::<Vec<_>>()
+            .chunks(self.n as usize)
+            .map(|chunk| chunk.to_vec())
+            .collect()
+    }
+    /// Render the finalized `n x n` matrix as CSV, with a header row and
+    /// a leading column of row labels.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("r\\c");
+        for c in 1..=self.n {
+            csv.push_str(&format!(",{c}"));
+        }
+        csv.push('\n');
+        for (r, row) in self.rows().into_iter().enumerate() {
+            csv.push_str(&(r as i32 + 1).to_string());
+            for v in row {
+                csv.push_str(&format!(",{v}"));
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+    /// Render the finalized `n x n` matrix as nested JSON arrays.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.rows()).expect("a matrix of integers always serializes")
+    }
+}
+
+// uses a knight to generate a report, routing around `board`'s blocked
+// cells when supplied, and otherwise applying `budget` as a time limit
+// when supplied; `budget` is not applied when `board` is set. Obstacle
+// avoidance and the time budget are both BFS-only; `run_on` rejects any
+// other `mode` when either is set, so `mode` is only consulted here when
+// neither is present.
+fn report(
+    knight: &Ability,
+    goal: &Position,
+    board: Option<&Board>,
+    mode: Mode,
+    budget: Option<Duration>,
+) -> Report {
+    let start = Position(1, 1);
+    let step_count = match (board, budget) {
+        (Some(board), _) => knight.find_shortest_path_on(&start, goal, board).step_count(),
+        (None, Some(budget)) => match knight.find_shortest_path_budgeted(&start, goal, budget) {
+            Ok(path) => path.step_count(),
+            Err(_timeout) => NOT_SOLVED_WITHIN_BUDGET,
+        },
+        (None, None) => knight.find_shortest_path_with(&start, goal, mode).step_count(),
+    };
+    #[cfg(feature = "debug")]
+    println!("knight: {:?} steps: {}", &knight, step_count);
+    step_count
+}
+
+/// generates a series of reports using a series of knights
+/// starting with (1,1) and ending with (n-1, n-1). Errs when
+/// n is not within the range of 5..=25.
+pub fn run(n: i32) -> Result<Reports, Box<dyn Error>> {
+    run_on(n, None, Mode::default(), None)
+}
+
+/// Like [`run`], but knights route around `blocked` cells instead of
+/// assuming an empty board, resolve their path with the given search
+/// `mode`, and (if `budget` is set) give up after that much time,
+/// recording [`NOT_SOLVED_WITHIN_BUDGET`] instead of blocking
+/// indefinitely. `budget` is not applied when `blocked` is set: the
+/// obstacle search always runs to completion. Knights that can no
+/// longer reach the corner report `-1`, same as any other unreachable
+/// goal.
+pub fn run_on(
+    n: i32,
+    blocked: Option<HashSet<Position>>,
+    mode: Mode,
+    budget: Option<Duration>,
+) -> Result<Reports, Box<dyn Error>> {
+    if !(5..=25).contains(&n) {
+        return Err("n must be between 5 and 25".into());
+    }
+    if mode != Mode::default() && (blocked.is_some() || budget.is_some()) {
+        return Err("mode must be bfs when obstacles or a budget is set".into());
+    }
+    let mut knights = Vec::new();
+    let goal = Position(n, n);
+    let board = blocked.map(|blocked| Board::new(goal.clone(), blocked));
+    // only run unique knights (i.e., 1,3 and 3,1 are the same)
+    for r in 1..n {
+        for c in 1..n {
+            if r <= c {
+                knights.push(Ability(r, c));
+            }
+        }
+    }
+    let mut data = VecDeque::new();
+    for knight in knights {
+        #[cfg(feature = "debug")]
+        println!("---------------\n🟢 knight: {:?}", &knight);
+        data.push_back(report(&knight, &goal, board.as_ref(), mode, budget));
+    }
+
+    Ok(Reports { data, n: n - 1 })
+}
+
+// Specific to the problem
+pub fn knights_on_board(n: i32) -> Vec<Vec<i32>> {
+    match run(n) {
+        Ok(reports) => reports.finalize().to_2dvec(),
+        Err(e) => panic!("Error: {}", e),
+    }
+}
+// Parse an obstacle file of `row,col` pairs, one per line.
+pub fn parse_obstacles(path: &std::path::Path) -> Result<HashSet<Position>, Box<dyn Error>> {
+    std::fs::read_to_string(path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (r, c) = line
+                .split_once(',')
+                .ok_or_else(|| format!("expected `row,col`, got {line:?}"))?;
+            Ok(Position(r.trim().parse()?, c.trim().parse()?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cheapest_path_matches_bfs_under_uniform_cost() {
+        let knight = Ability(1, 2);
+        let start = Position(1, 1);
+        let goal = Position(8, 8);
+
+        let bfs = knight.find_shortest_path(&start, &goal);
+        let (cheapest, total) = knight.find_cheapest_path(&start, &goal, &|_| 1);
+
+        assert_eq!(bfs.step_count(), cheapest.step_count());
+        assert_eq!(total as i32, bfs.step_count());
+        assert_eq!(cheapest.total_cost(&|_| 1), total);
+    }
+
+    // Small deterministic PRNG so the triples below vary without pulling
+    // in an extra dependency just for a test.
+    fn next_rand(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn bidirectional_matches_bfs_on_random_triples() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..20 {
+            let a = 1 + (next_rand(&mut state) % 3) as i32;
+            let b = 1 + (next_rand(&mut state) % 3) as i32;
+            let n = 5 + (next_rand(&mut state) % 20) as i32;
+            let start = Position(
+                1 + (next_rand(&mut state) % n as u64) as i32,
+                1 + (next_rand(&mut state) % n as u64) as i32,
+            );
+            let goal = Position(
+                1 + (next_rand(&mut state) % n as u64) as i32,
+                1 + (next_rand(&mut state) % n as u64) as i32,
+            );
+
+            let knight = Ability(a, b);
+            let bfs = knight.find_shortest_path(&start, &goal);
+            let bidi = knight.find_shortest_path_bidirectional(&start, &goal);
+            assert_eq!(
+                bfs.step_count(),
+                bidi.step_count(),
+                "knight {knight:?} start {start:?} goal {goal:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn budgeted_search_times_out_on_a_large_board_with_a_tiny_budget() {
+        let knight = Ability(1, 2);
+        let start = Position(1, 1);
+        let goal = Position(1000, 1000);
+
+        let result = knight.find_shortest_path_budgeted(&start, &goal, Duration::from_nanos(1));
+
+        assert!(matches!(result, Err(SearchTimeout { .. })));
+    }
+}